@@ -0,0 +1,130 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines aggregate kernels that reduce a `PrimitiveArray` to a single scalar.
+
+use num::traits::WrappingAdd;
+
+use crate::array::PrimitiveArray;
+use crate::types::NativeType;
+
+/// Returns the sum of all the valid values in `array`, or `None` if the array is
+/// empty or all values are null. Integer sums wrap on overflow, as the
+/// `wrapping_add` arithmetic kernel does.
+pub fn sum<T>(array: &PrimitiveArray<T>) -> Option<T>
+where
+    T: NativeType + WrappingAdd + num::Zero,
+{
+    if array.len() == 0 || array.null_count() == array.len() {
+        return None;
+    }
+
+    let sum = if array.null_count() == 0 {
+        array
+            .values()
+            .iter()
+            .fold(T::zero(), |acc, value| acc.wrapping_add(value))
+    } else {
+        let validity = array.validity().as_ref().unwrap();
+        array
+            .values()
+            .iter()
+            .zip(validity.iter())
+            .filter(|(_, is_valid)| *is_valid)
+            .fold(T::zero(), |acc, (value, _)| acc.wrapping_add(value))
+    };
+    Some(sum)
+}
+
+/// Returns the minimum valid value in `array`, or `None` if the array is empty or
+/// all values are null.
+pub fn min<T>(array: &PrimitiveArray<T>) -> Option<T>
+where
+    T: NativeType + PartialOrd,
+{
+    reduce(array, |a, b| if a < b { a } else { b })
+}
+
+/// Returns the maximum valid value in `array`, or `None` if the array is empty or
+/// all values are null.
+pub fn max<T>(array: &PrimitiveArray<T>) -> Option<T>
+where
+    T: NativeType + PartialOrd,
+{
+    reduce(array, |a, b| if a > b { a } else { b })
+}
+
+#[inline]
+fn reduce<T, F>(array: &PrimitiveArray<T>, op: F) -> Option<T>
+where
+    T: NativeType,
+    F: Fn(T, T) -> T,
+{
+    if array.len() == 0 || array.null_count() == array.len() {
+        return None;
+    }
+
+    if array.null_count() == 0 {
+        array.values().iter().copied().reduce(op)
+    } else {
+        let validity = array.validity().as_ref().unwrap();
+        array
+            .values()
+            .iter()
+            .zip(validity.iter())
+            .filter(|(_, is_valid)| *is_valid)
+            .map(|(value, _)| *value)
+            .reduce(op)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Primitive;
+    use crate::datatypes::DataType;
+
+    #[test]
+    fn test_sum() {
+        let a = Primitive::from(&vec![Some(1i32), Some(2), None, Some(3)]).to(DataType::Int32);
+        assert_eq!(sum(&a), Some(6));
+    }
+
+    #[test]
+    fn test_sum_all_null() {
+        let a = Primitive::from(&vec![None, None::<i32>]).to(DataType::Int32);
+        assert_eq!(sum(&a), None);
+    }
+
+    #[test]
+    fn test_sum_empty() {
+        let a = Primitive::from(&Vec::<Option<i32>>::new()).to(DataType::Int32);
+        assert_eq!(sum(&a), None);
+    }
+
+    #[test]
+    fn test_min() {
+        let a = Primitive::from(&vec![Some(3i32), None, Some(1), Some(2)]).to(DataType::Int32);
+        assert_eq!(min(&a), Some(1));
+    }
+
+    #[test]
+    fn test_max() {
+        let a = Primitive::from(&vec![Some(3i32), None, Some(1), Some(2)]).to(DataType::Int32);
+        assert_eq!(max(&a), Some(3));
+    }
+}
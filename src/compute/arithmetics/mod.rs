@@ -17,14 +17,20 @@
 
 //! Defines basic arithmetic kernels for `PrimitiveArrays`.
 
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
-use num::{traits::Pow, Zero};
+use num::{
+    traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, Pow, Saturating},
+    traits::{WrappingAdd, WrappingMul, WrappingSub},
+    Zero,
+};
 
 use crate::array::*;
+use crate::bitmap::{Bitmap, MutableBitmap};
 use crate::buffer::Buffer;
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
+use crate::scalar::{PrimitiveScalar, Scalar};
 use crate::types::NativeType;
 
 use super::arity::{binary, unary};
@@ -130,6 +136,7 @@ pub enum Operator {
     Subtract,
     Multiply,
     Divide,
+    Remainder,
 }
 
 #[inline]
@@ -139,13 +146,20 @@ fn arithmetic_primitive<T>(
     rhs: &PrimitiveArray<T>,
 ) -> Result<PrimitiveArray<T>>
 where
-    T: NativeType + Div<Output = T> + Zero + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    T: NativeType
+        + Div<Output = T>
+        + Zero
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Rem<Output = T>,
 {
     match op {
         Operator::Add => add(lhs, rhs),
         Operator::Subtract => subtract(lhs, rhs),
         Operator::Multiply => multiply(lhs, rhs),
         Operator::Divide => divide(lhs, rhs),
+        Operator::Remainder => remainder(lhs, rhs),
     }
 }
 
@@ -156,13 +170,253 @@ pub fn arithmetic_primitive_scalar<T>(
     rhs: &T,
 ) -> Result<PrimitiveArray<T>>
 where
-    T: NativeType + Div<Output = T> + Zero + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    T: NativeType
+        + Div<Output = T>
+        + Zero
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Rem<Output = T>,
 {
     match op {
         Operator::Add => Ok(add_scalar(lhs, rhs)),
         Operator::Subtract => Ok(subtract_scalar(lhs, rhs)),
         Operator::Multiply => Ok(multiply_scalar(lhs, rhs)),
         Operator::Divide => divide_scalar(lhs, rhs),
+        Operator::Remainder => remainder_scalar(lhs, rhs),
+    }
+}
+
+/// Evaluates `op` between `array` and `scalar`, dispatching on their common
+/// `DataType`. `scalar` is the right-hand operand, so e.g. `Operator::Subtract`
+/// computes `array - scalar`. A null `scalar` produces an all-null output.
+pub fn arithmetic_scalar(
+    array: &dyn Array,
+    op: Operator,
+    scalar: &dyn Scalar,
+) -> Result<Box<dyn Array>> {
+    let data_type = array.data_type();
+    if data_type != scalar.data_type() {
+        return Err(ArrowError::NotYetImplemented(
+            "Arithmetic is currently only supported for an array and a scalar of the same logical type"
+                .to_string(),
+        ));
+    }
+    match data_type {
+        DataType::Int8 => arithmetic_scalar_dyn::<i8>(array, op, scalar),
+        DataType::Int16 => arithmetic_scalar_dyn::<i16>(array, op, scalar),
+        DataType::Int32 => arithmetic_scalar_dyn::<i32>(array, op, scalar),
+        DataType::Int64 | DataType::Duration(_) => arithmetic_scalar_dyn::<i64>(array, op, scalar),
+        DataType::UInt8 => arithmetic_scalar_dyn::<u8>(array, op, scalar),
+        DataType::UInt16 => arithmetic_scalar_dyn::<u16>(array, op, scalar),
+        DataType::UInt32 => arithmetic_scalar_dyn::<u32>(array, op, scalar),
+        DataType::UInt64 => arithmetic_scalar_dyn::<u64>(array, op, scalar),
+        DataType::Float32 => arithmetic_scalar_dyn::<f32>(array, op, scalar),
+        DataType::Float64 => arithmetic_scalar_dyn::<f64>(array, op, scalar),
+        DataType::Decimal(_, _) => arithmetic_scalar_dyn::<i128>(array, op, scalar),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Arithmetics between {:?} and a scalar is not supported",
+            data_type
+        ))),
+    }
+}
+
+/// Evaluates `op` between `scalar` and `array`, dispatching on their common
+/// `DataType`. `scalar` is the left-hand operand, so e.g. `Operator::Subtract`
+/// computes `scalar - array`. A null `scalar` produces an all-null output.
+pub fn scalar_arithmetic(
+    scalar: &dyn Scalar,
+    op: Operator,
+    array: &dyn Array,
+) -> Result<Box<dyn Array>> {
+    match op {
+        // commutative: `scalar op array` is the same as `array op scalar`
+        Operator::Add | Operator::Multiply => arithmetic_scalar(array, op, scalar),
+        _ => {
+            let data_type = array.data_type();
+            if data_type != scalar.data_type() {
+                return Err(ArrowError::NotYetImplemented(
+                    "Arithmetic is currently only supported for an array and a scalar of the same logical type"
+                        .to_string(),
+                ));
+            }
+            match data_type {
+                DataType::Int8 => scalar_arithmetic_dyn::<i8>(scalar, op, array),
+                DataType::Int16 => scalar_arithmetic_dyn::<i16>(scalar, op, array),
+                DataType::Int32 => scalar_arithmetic_dyn::<i32>(scalar, op, array),
+                DataType::Int64 | DataType::Duration(_) => {
+                    scalar_arithmetic_dyn::<i64>(scalar, op, array)
+                }
+                DataType::UInt8 => scalar_arithmetic_dyn::<u8>(scalar, op, array),
+                DataType::UInt16 => scalar_arithmetic_dyn::<u16>(scalar, op, array),
+                DataType::UInt32 => scalar_arithmetic_dyn::<u32>(scalar, op, array),
+                DataType::UInt64 => scalar_arithmetic_dyn::<u64>(scalar, op, array),
+                DataType::Float32 => scalar_arithmetic_dyn::<f32>(scalar, op, array),
+                DataType::Float64 => scalar_arithmetic_dyn::<f64>(scalar, op, array),
+                DataType::Decimal(_, _) => scalar_arithmetic_dyn::<i128>(scalar, op, array),
+                _ => Err(ArrowError::NotYetImplemented(format!(
+                    "Arithmetics between a scalar and {:?} is not supported",
+                    data_type
+                ))),
+            }
+        }
+    }
+}
+
+#[inline]
+fn arithmetic_scalar_dyn<T>(
+    array: &dyn Array,
+    op: Operator,
+    scalar: &dyn Scalar,
+) -> Result<Box<dyn Array>>
+where
+    T: NativeType
+        + Div<Output = T>
+        + Zero
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Rem<Output = T>,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let scalar = scalar.as_any().downcast_ref::<PrimitiveScalar<T>>().unwrap();
+    match scalar.value() {
+        Some(value) => arithmetic_primitive_scalar(array, op, value)
+            .map(Box::new)
+            .map(|x| x as Box<dyn Array>),
+        None => Ok(Box::new(PrimitiveArray::<T>::new_null(
+            array.data_type().clone(),
+            array.len(),
+        ))),
+    }
+}
+
+#[inline]
+fn scalar_arithmetic_dyn<T>(
+    scalar: &dyn Scalar,
+    op: Operator,
+    array: &dyn Array,
+) -> Result<Box<dyn Array>>
+where
+    T: NativeType
+        + Div<Output = T>
+        + Zero
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Rem<Output = T>,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let scalar = scalar.as_any().downcast_ref::<PrimitiveScalar<T>>().unwrap();
+    match scalar.value() {
+        Some(value) => arithmetic_primitive_scalar_left(value, op, array)
+            .map(Box::new)
+            .map(|x| x as Box<dyn Array>),
+        None => Ok(Box::new(PrimitiveArray::<T>::new_null(
+            array.data_type().clone(),
+            array.len(),
+        ))),
+    }
+}
+
+/// Computes `lhs op array`, i.e. `lhs` is the left-hand operand of a non-commutative
+/// operator such as subtraction or division.
+#[inline]
+fn arithmetic_primitive_scalar_left<T>(
+    lhs: &T,
+    op: Operator,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType
+        + Div<Output = T>
+        + Zero
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Rem<Output = T>,
+{
+    let lhs = *lhs;
+    match op {
+        Operator::Add => Ok(add_scalar(rhs, &lhs)),
+        Operator::Multiply => Ok(multiply_scalar(rhs, &lhs)),
+        Operator::Subtract => Ok(unary(rhs, |x| lhs - x, rhs.data_type())),
+        Operator::Divide => scalar_left_zero_guarded(lhs, rhs, |l, r| l / r),
+        Operator::Remainder => scalar_left_zero_guarded(lhs, rhs, |l, r| l % r),
+    }
+}
+
+/// Computes `op(lhs, rhs[i])` for each valid slot of `rhs`, erroring if a valid slot
+/// is zero (a division/remainder by zero); null slots, which store a `T::default()`
+/// placeholder, are passed through as null without being inspected.
+#[inline]
+fn scalar_left_zero_guarded<T, F>(
+    lhs: T,
+    rhs: &PrimitiveArray<T>,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + Zero,
+    F: Fn(T, T) -> T,
+{
+    let validity = rhs.validity();
+
+    let values = if let Some(b) = validity {
+        // there are nulls. Division by zero on them should be ignored
+        let values = b.iter().zip(rhs.values().iter()).map(|(is_valid, r)| {
+            if is_valid {
+                if r.is_zero() {
+                    Err(ArrowError::InvalidArgumentError(
+                        "There is a zero in the division, causing a division by zero"
+                            .to_string(),
+                    ))
+                } else {
+                    Ok(op(lhs, *r))
+                }
+            } else {
+                Ok(T::default())
+            }
+        });
+        unsafe { Buffer::try_from_trusted_len_iter(values) }
+    } else {
+        // no value is null
+        let values = rhs.values().iter().map(|r| {
+            if r.is_zero() {
+                Err(ArrowError::InvalidArgumentError(
+                    "There is a zero in the division, causing a division by zero".to_string(),
+                ))
+            } else {
+                Ok(op(lhs, *r))
+            }
+        });
+        unsafe { Buffer::try_from_trusted_len_iter(values) }
+    }?;
+
+    Ok(PrimitiveArray::<T>::from_data(
+        rhs.data_type().clone(),
+        values,
+        rhs.validity().clone(),
+    ))
+}
+
+/// Checked arithmetic between two arrays: overflowing or invalid operations (e.g. a
+/// division by zero) result in a null slot in the output rather than an error or a
+/// wrapped value.
+#[inline]
+pub fn checked_arithmetic<T>(
+    lhs: &PrimitiveArray<T>,
+    op: Operator,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + CheckedRem,
+{
+    match op {
+        Operator::Add => checked_add(lhs, rhs),
+        Operator::Subtract => checked_sub(lhs, rhs),
+        Operator::Multiply => checked_mul(lhs, rhs),
+        Operator::Divide => checked_div(lhs, rhs),
+        Operator::Remainder => checked_rem(lhs, rhs),
     }
 }
 
@@ -242,6 +496,242 @@ where
     Ok(unary(array, |x| x / divisor, array.data_type()))
 }
 
+/// Remainder of two arrays.
+///
+/// # Errors
+///
+/// This function errors iff:
+/// * the arrays have different lengths
+/// * a division by zero is found
+fn remainder<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    T: Rem<Output = T> + Zero,
+{
+    if lhs.len() != rhs.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Cannot perform math operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = if let Some(b) = &validity {
+        // there are nulls. Division by zero on them should be ignored
+        let values =
+            b.iter()
+                .zip(lhs.values().iter().zip(rhs.values()))
+                .map(|(is_valid, (lhs, rhs))| {
+                    if is_valid {
+                        if rhs.is_zero() {
+                            Err(ArrowError::InvalidArgumentError(
+                                "There is a zero in the division, causing a division by zero"
+                                    .to_string(),
+                            ))
+                        } else {
+                            Ok(*lhs % *rhs)
+                        }
+                    } else {
+                        Ok(T::default())
+                    }
+                });
+        unsafe { Buffer::try_from_trusted_len_iter(values) }
+    } else {
+        // no value is null
+        let values = lhs.values().iter().zip(rhs.values()).map(|(lhs, rhs)| {
+            if rhs.is_zero() {
+                Err(ArrowError::InvalidArgumentError(
+                    "There is a zero in the division, causing a division by zero".to_string(),
+                ))
+            } else {
+                Ok(*lhs % *rhs)
+            }
+        });
+        unsafe { Buffer::try_from_trusted_len_iter(values) }
+    }?;
+
+    Ok(PrimitiveArray::<T>::from_data(
+        lhs.data_type().clone(),
+        values,
+        validity,
+    ))
+}
+
+/// Remainder of an array by a constant
+pub fn remainder_scalar<T>(array: &PrimitiveArray<T>, divisor: &T) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    T: Rem<Output = T> + Zero,
+{
+    if divisor.is_zero() {
+        return Err(ArrowError::InvalidArgumentError(
+            "The divisor cannot be zero".to_string(),
+        ));
+    }
+    let divisor = *divisor;
+    Ok(unary(array, |x| x % divisor, array.data_type()))
+}
+
+/// Applies a fallible, checked binary operation to two arrays of the same length,
+/// nulling out any position where the operation overflows (or is otherwise invalid,
+/// e.g. a division by zero) in addition to positions that were already null in either
+/// input.
+#[inline]
+fn checked_binary<T, F>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    F: Fn(&T, &T) -> Option<T>,
+{
+    if lhs.len() != rhs.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Cannot perform math operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let mut overflow = MutableBitmap::with_capacity(lhs.len());
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .enumerate()
+        .map(|(i, (l, r))| {
+            let is_valid = validity.as_ref().map(|x| x.get_bit(i)).unwrap_or(true);
+            if !is_valid {
+                overflow.push(true);
+                T::default()
+            } else {
+                match op(l, r) {
+                    Some(value) => {
+                        overflow.push(true);
+                        value
+                    }
+                    None => {
+                        overflow.push(false);
+                        T::default()
+                    }
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let overflow: Option<Bitmap> = Some(overflow.into());
+    let validity = combine_validities(&validity, &overflow);
+
+    Ok(PrimitiveArray::<T>::from_data(
+        lhs.data_type().clone(),
+        values.into(),
+        validity,
+    ))
+}
+
+/// Checked add of two arrays. Overflowing positions are null in the result.
+pub fn checked_add<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + CheckedAdd,
+{
+    checked_binary(lhs, rhs, |l, r| l.checked_add(r))
+}
+
+/// Checked subtraction of two arrays. Overflowing positions are null in the result.
+pub fn checked_sub<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + CheckedSub,
+{
+    checked_binary(lhs, rhs, |l, r| l.checked_sub(r))
+}
+
+/// Checked multiplication of two arrays. Overflowing positions are null in the result.
+pub fn checked_mul<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + CheckedMul,
+{
+    checked_binary(lhs, rhs, |l, r| l.checked_mul(r))
+}
+
+/// Checked division of two arrays. Overflowing positions and divisions by zero are
+/// null in the result.
+pub fn checked_div<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + CheckedDiv,
+{
+    checked_binary(lhs, rhs, |l, r| l.checked_div(r))
+}
+
+/// Checked remainder of two arrays. Overflowing positions and divisions by zero are
+/// null in the result.
+pub fn checked_rem<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + CheckedRem,
+{
+    checked_binary(lhs, rhs, |l, r| l.checked_rem(r))
+}
+
+/// Wrapping addition of two arrays. Overflow wraps around instead of erroring or
+/// nulling the result.
+pub fn wrapping_add<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + WrappingAdd,
+{
+    binary(lhs, rhs, |a, b| a.wrapping_add(&b))
+}
+
+/// Wrapping subtraction of two arrays. Overflow wraps around instead of erroring or
+/// nulling the result.
+pub fn wrapping_sub<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + WrappingSub,
+{
+    binary(lhs, rhs, |a, b| a.wrapping_sub(&b))
+}
+
+/// Wrapping multiplication of two arrays. Overflow wraps around instead of erroring or
+/// nulling the result.
+pub fn wrapping_mul<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + WrappingMul,
+{
+    binary(lhs, rhs, |a, b| a.wrapping_mul(&b))
+}
+
+/// Saturating addition of two arrays. Overflow clamps to the type's bounds instead of
+/// erroring or nulling the result.
+pub fn saturating_add<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + Saturating,
+{
+    binary(lhs, rhs, |a, b| a.saturating_add(b))
+}
+
+/// Saturating subtraction of two arrays. Overflow clamps to the type's bounds instead
+/// of erroring or nulling the result.
+pub fn saturating_sub<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + Saturating,
+{
+    binary(lhs, rhs, |a, b| a.saturating_sub(b))
+}
+
 #[inline]
 pub fn add<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
 where
@@ -389,4 +879,84 @@ mod tests {
         let expected = Primitive::from(&vec![Some(4f32), None]).to(DataType::Float32);
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn test_checked_add_overflow() {
+        let a = Primitive::from(&vec![Some(i8::MAX), Some(1), None]).to(DataType::Int8);
+        let b = Primitive::from(&vec![Some(1), Some(1), Some(1)]).to(DataType::Int8);
+        let result = checked_add(&a, &b).unwrap();
+        let expected = Primitive::from(&vec![None, Some(2), None]).to(DataType::Int8);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_checked_div_by_zero() {
+        let a = Primitive::from(&vec![Some(10), Some(10)]).to(DataType::Int32);
+        let b = Primitive::from(&vec![Some(0), Some(5)]).to(DataType::Int32);
+        let result = checked_div(&a, &b).unwrap();
+        let expected = Primitive::from(&vec![None, Some(2)]).to(DataType::Int32);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_wrapping_add_overflow() {
+        let a = Primitive::from(&vec![Some(i8::MAX), Some(1)]).to(DataType::Int8);
+        let b = Primitive::from(&vec![Some(1), Some(1)]).to(DataType::Int8);
+        let result = wrapping_add(&a, &b).unwrap();
+        let expected = Primitive::from(&vec![Some(i8::MIN), Some(2)]).to(DataType::Int8);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_saturating_add_overflow() {
+        let a = Primitive::from(&vec![Some(i8::MAX), Some(1)]).to(DataType::Int8);
+        let b = Primitive::from(&vec![Some(1), Some(1)]).to(DataType::Int8);
+        let result = saturating_add(&a, &b).unwrap();
+        let expected = Primitive::from(&vec![Some(i8::MAX), Some(2)]).to(DataType::Int8);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_remainder() {
+        let a = Primitive::from(&vec![None, Some(7), None, Some(7)]).to(DataType::Int32);
+        let b = Primitive::from(&vec![Some(5), None, None, Some(5)]).to(DataType::Int32);
+        let result = remainder(&a, &b).unwrap();
+        let expected = Primitive::from(&vec![None, None, None, Some(2)]).to(DataType::Int32);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_arithmetic_scalar() {
+        let a = Primitive::from(&vec![Some(10), None, Some(6)]).to(DataType::Int32);
+        let scalar = PrimitiveScalar::new(DataType::Int32, Some(2));
+        let result = arithmetic_scalar(&a, Operator::Divide, &scalar).unwrap();
+        let expected = Primitive::from(&vec![Some(5), None, Some(3)]).to(DataType::Int32);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn test_scalar_arithmetic_subtract() {
+        let a = Primitive::from(&vec![Some(4), None, Some(1)]).to(DataType::Int32);
+        let scalar = PrimitiveScalar::new(DataType::Int32, Some(10));
+        let result = scalar_arithmetic(&scalar, Operator::Subtract, &a).unwrap();
+        let expected = Primitive::from(&vec![Some(6), None, Some(9)]).to(DataType::Int32);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
+
+    #[test]
+    fn test_scalar_arithmetic_divide_by_zero_on_null() {
+        let a = Primitive::from(&vec![None]).to(DataType::Int32);
+        let scalar = PrimitiveScalar::new(DataType::Int32, Some(10));
+        let result = scalar_arithmetic(&scalar, Operator::Divide, &a);
+        assert_eq!(result.is_err(), false);
+    }
+
+    #[test]
+    fn test_arithmetic_scalar_null() {
+        let a = Primitive::from(&vec![Some(4), Some(1)]).to(DataType::Int32);
+        let scalar = PrimitiveScalar::new(DataType::Int32, None);
+        let result = arithmetic_scalar(&a, Operator::Add, &scalar).unwrap();
+        let expected = Primitive::from(&vec![None::<i32>, None]).to(DataType::Int32);
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
 }
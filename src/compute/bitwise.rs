@@ -0,0 +1,239 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines bitwise kernels for integer `PrimitiveArrays`.
+
+use std::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+
+use crate::array::*;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::arity::{binary, unary};
+
+/// A bitwise operator applicable to integer `PrimitiveArray`s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BitwiseOperator {
+    And,
+    Or,
+    Xor,
+}
+
+/// Evaluates `op` between `lhs` and `rhs`, dispatching on the common `DataType` of
+/// both arrays. Errs if `lhs` and `rhs` have different `DataType`s or if the
+/// `DataType` is not an integer.
+pub fn bitwise(lhs: &dyn Array, op: BitwiseOperator, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    let data_type = lhs.data_type();
+    if data_type != rhs.data_type() {
+        return Err(ArrowError::NotYetImplemented(
+            "Bitwise operations are currently only supported for arrays of the same logical type"
+                .to_string(),
+        ));
+    }
+    match data_type {
+        DataType::Int8 => {
+            let lhs = lhs.as_any().downcast_ref::<Int8Array>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<Int8Array>().unwrap();
+            bitwise_primitive(lhs, op, rhs)
+                .map(Box::new)
+                .map(|x| x as Box<dyn Array>)
+        }
+        DataType::Int16 => {
+            let lhs = lhs.as_any().downcast_ref::<Int16Array>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<Int16Array>().unwrap();
+            bitwise_primitive(lhs, op, rhs)
+                .map(Box::new)
+                .map(|x| x as Box<dyn Array>)
+        }
+        DataType::Int32 => {
+            let lhs = lhs.as_any().downcast_ref::<Int32Array>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<Int32Array>().unwrap();
+            bitwise_primitive(lhs, op, rhs)
+                .map(Box::new)
+                .map(|x| x as Box<dyn Array>)
+        }
+        DataType::Int64 => {
+            let lhs = lhs.as_any().downcast_ref::<Int64Array>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<Int64Array>().unwrap();
+            bitwise_primitive(lhs, op, rhs)
+                .map(Box::new)
+                .map(|x| x as Box<dyn Array>)
+        }
+        DataType::UInt8 => {
+            let lhs = lhs.as_any().downcast_ref::<UInt8Array>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<UInt8Array>().unwrap();
+            bitwise_primitive(lhs, op, rhs)
+                .map(Box::new)
+                .map(|x| x as Box<dyn Array>)
+        }
+        DataType::UInt16 => {
+            let lhs = lhs.as_any().downcast_ref::<UInt16Array>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<UInt16Array>().unwrap();
+            bitwise_primitive(lhs, op, rhs)
+                .map(Box::new)
+                .map(|x| x as Box<dyn Array>)
+        }
+        DataType::UInt32 => {
+            let lhs = lhs.as_any().downcast_ref::<UInt32Array>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<UInt32Array>().unwrap();
+            bitwise_primitive(lhs, op, rhs)
+                .map(Box::new)
+                .map(|x| x as Box<dyn Array>)
+        }
+        DataType::UInt64 => {
+            let lhs = lhs.as_any().downcast_ref::<UInt64Array>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<UInt64Array>().unwrap();
+            bitwise_primitive(lhs, op, rhs)
+                .map(Box::new)
+                .map(|x| x as Box<dyn Array>)
+        }
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Bitwise operations between {:?} is not supported",
+            data_type
+        ))),
+    }
+}
+
+#[inline]
+fn bitwise_primitive<T>(
+    lhs: &PrimitiveArray<T>,
+    op: BitwiseOperator,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + BitAnd<Output = T> + BitOr<Output = T> + BitXor<Output = T>,
+{
+    match op {
+        BitwiseOperator::And => and(lhs, rhs),
+        BitwiseOperator::Or => or(lhs, rhs),
+        BitwiseOperator::Xor => xor(lhs, rhs),
+    }
+}
+
+/// Bitwise "and" between two integer arrays.
+pub fn and<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + BitAnd<Output = T>,
+{
+    binary(lhs, rhs, |a, b| a & b)
+}
+
+/// Bitwise "or" between two integer arrays.
+pub fn or<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + BitOr<Output = T>,
+{
+    binary(lhs, rhs, |a, b| a | b)
+}
+
+/// Bitwise "xor" between two integer arrays.
+pub fn xor<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + BitXor<Output = T>,
+{
+    binary(lhs, rhs, |a, b| a ^ b)
+}
+
+/// Bitwise "not" of an integer array.
+pub fn not<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Not<Output = T>,
+{
+    unary(array, |a| !a, array.data_type())
+}
+
+/// Shifts the bits of `lhs` to the left by the amounts in `rhs`, element-wise.
+pub fn shift_left<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + Shl<Output = T>,
+{
+    binary(lhs, rhs, |a, b| a << b)
+}
+
+/// Shifts the bits of `lhs` to the right by the amounts in `rhs`, element-wise.
+pub fn shift_right<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + Shr<Output = T>,
+{
+    binary(lhs, rhs, |a, b| a >> b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::DataType;
+
+    #[test]
+    fn test_and() {
+        let a = Primitive::from(&vec![None, Some(6), None, Some(6)]).to(DataType::Int32);
+        let b = Primitive::from(&vec![Some(5), None, None, Some(6)]).to(DataType::Int32);
+        let result = and(&a, &b).unwrap();
+        let expected = Primitive::from(&vec![None, None, None, Some(6)]).to(DataType::Int32);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_or() {
+        let a = Primitive::from(&vec![Some(1), Some(2)]).to(DataType::Int32);
+        let b = Primitive::from(&vec![Some(2), Some(1)]).to(DataType::Int32);
+        let result = or(&a, &b).unwrap();
+        let expected = Primitive::from(&vec![Some(3), Some(3)]).to(DataType::Int32);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_xor() {
+        let a = Primitive::from(&vec![Some(5), Some(6)]).to(DataType::Int32);
+        let b = Primitive::from(&vec![Some(5), Some(3)]).to(DataType::Int32);
+        let result = xor(&a, &b).unwrap();
+        let expected = Primitive::from(&vec![Some(0), Some(5)]).to(DataType::Int32);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_not() {
+        let a = Primitive::from(&vec![Some(0i32), None]).to(DataType::Int32);
+        let result = not(&a);
+        let expected = Primitive::from(&vec![Some(-1i32), None]).to(DataType::Int32);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_shift_left() {
+        let a = Primitive::from(&vec![Some(1i32), Some(2)]).to(DataType::Int32);
+        let b = Primitive::from(&vec![Some(2i32), Some(1)]).to(DataType::Int32);
+        let result = shift_left(&a, &b).unwrap();
+        let expected = Primitive::from(&vec![Some(4i32), Some(4)]).to(DataType::Int32);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn test_shift_right() {
+        let a = Primitive::from(&vec![Some(4i32), Some(8)]).to(DataType::Int32);
+        let b = Primitive::from(&vec![Some(2i32), Some(1)]).to(DataType::Int32);
+        let result = shift_right(&a, &b).unwrap();
+        let expected = Primitive::from(&vec![Some(1i32), Some(4)]).to(DataType::Int32);
+        assert_eq!(result, expected)
+    }
+}
@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::{
+    array::{Offset, PrimitiveArray},
+    bitmap::{Bitmap, MutableBitmap},
+    buffer::Buffer,
+    error::{ArrowError, Result},
+};
+
+use super::maybe_usize;
+
+/// Gathers the variable-length slots described by `offsets`/`values` at the
+/// positions given by `indices`, returning the raw parts (offsets, values, validity)
+/// of a new `Utf8Array`/`BinaryArray`. Shared by both since they only differ in the
+/// `DataType` they are reconstructed with.
+pub fn take_from_parts<O: Offset, I: Offset>(
+    offsets: &[O],
+    values: &[u8],
+    array_validity: Option<&Bitmap>,
+    indices: &PrimitiveArray<I>,
+) -> Result<(Buffer<O>, Buffer<u8>, Option<Bitmap>)> {
+    let indices_validity = indices.validity();
+
+    let mut new_offsets = Vec::<O>::with_capacity(indices.len() + 1);
+    new_offsets.push(O::default());
+    let mut new_values = Vec::<u8>::new();
+    let mut validity = MutableBitmap::with_capacity(indices.len());
+
+    for (i, index) in indices.values().iter().enumerate() {
+        let is_index_valid = indices_validity
+            .as_ref()
+            .map(|v| v.get_bit(i))
+            .unwrap_or(true);
+
+        if is_index_valid {
+            let index = maybe_usize::<I>(*index)?;
+            let is_value_valid = array_validity.map(|v| v.get_bit(index)).unwrap_or(true);
+            validity.push(is_value_valid);
+            if is_value_valid {
+                let start = maybe_usize::<O>(offsets[index])?;
+                let end = maybe_usize::<O>(offsets[index + 1])?;
+                new_values.extend_from_slice(&values[start..end]);
+            }
+        } else {
+            validity.push(false);
+        }
+
+        let length = O::from_usize(new_values.len()).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(
+                "Result of `take` on a variable-length array overflows its offset type"
+                    .to_string(),
+            )
+        })?;
+        new_offsets.push(length);
+    }
+
+    Ok((new_offsets.into(), new_values.into(), validity.into()))
+}
@@ -0,0 +1,151 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines take kernels for `Array`s, selecting/reordering rows by index.
+
+mod boolean;
+mod generic_binary;
+mod primitive;
+
+use crate::array::*;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// Converts an `Offset`-typed index into a `usize`, erroring on an index that does
+/// not fit (e.g. a negative offset).
+#[inline]
+fn maybe_usize<I: Offset>(index: I) -> Result<usize> {
+    index.to_usize().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!("Take index {:?} is out of range", index))
+    })
+}
+
+/// Takes the rows of `values` at the positions given by `indices`, returning a new
+/// `Array` of the same logical type as `values`. A null index produces a null row in
+/// the output; a negative index is an error and an out-of-bounds, non-null index panics.
+pub fn take<I: Offset>(values: &dyn Array, indices: &PrimitiveArray<I>) -> Result<Box<dyn Array>> {
+    match values.data_type() {
+        DataType::Int8 => {
+            let values = values.as_any().downcast_ref::<Int8Array>().unwrap();
+            primitive::take::<i8, I>(values, indices).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::Int16 => {
+            let values = values.as_any().downcast_ref::<Int16Array>().unwrap();
+            primitive::take::<i16, I>(values, indices).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::Int32 => {
+            let values = values.as_any().downcast_ref::<Int32Array>().unwrap();
+            primitive::take::<i32, I>(values, indices).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::Int64 | DataType::Duration(_) => {
+            let values = values.as_any().downcast_ref::<Int64Array>().unwrap();
+            primitive::take::<i64, I>(values, indices).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::UInt8 => {
+            let values = values.as_any().downcast_ref::<UInt8Array>().unwrap();
+            primitive::take::<u8, I>(values, indices).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::UInt16 => {
+            let values = values.as_any().downcast_ref::<UInt16Array>().unwrap();
+            primitive::take::<u16, I>(values, indices).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::UInt32 => {
+            let values = values.as_any().downcast_ref::<UInt32Array>().unwrap();
+            primitive::take::<u32, I>(values, indices).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::UInt64 => {
+            let values = values.as_any().downcast_ref::<UInt64Array>().unwrap();
+            primitive::take::<u64, I>(values, indices).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::Float32 => {
+            let values = values.as_any().downcast_ref::<Float32Array>().unwrap();
+            primitive::take::<f32, I>(values, indices).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::Float64 => {
+            let values = values.as_any().downcast_ref::<Float64Array>().unwrap();
+            primitive::take::<f64, I>(values, indices).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::Boolean => {
+            let values = values.as_any().downcast_ref::<BooleanArray>().unwrap();
+            boolean::take::<I>(values, indices).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::Utf8 => {
+            let array = values.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            let (offsets, values, validity) = generic_binary::take_from_parts::<i32, I>(
+                array.offsets(),
+                array.values(),
+                array.validity().as_ref(),
+                indices,
+            )?;
+            Ok(Box::new(Utf8Array::<i32>::from_data(
+                array.data_type().clone(),
+                offsets,
+                values,
+                validity,
+            )) as Box<dyn Array>)
+        }
+        DataType::LargeUtf8 => {
+            let array = values.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            let (offsets, values, validity) = generic_binary::take_from_parts::<i64, I>(
+                array.offsets(),
+                array.values(),
+                array.validity().as_ref(),
+                indices,
+            )?;
+            Ok(Box::new(Utf8Array::<i64>::from_data(
+                array.data_type().clone(),
+                offsets,
+                values,
+                validity,
+            )) as Box<dyn Array>)
+        }
+        DataType::Binary => {
+            let array = values.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            let (offsets, values, validity) = generic_binary::take_from_parts::<i32, I>(
+                array.offsets(),
+                array.values(),
+                array.validity().as_ref(),
+                indices,
+            )?;
+            Ok(Box::new(BinaryArray::<i32>::from_data(
+                array.data_type().clone(),
+                offsets,
+                values,
+                validity,
+            )) as Box<dyn Array>)
+        }
+        DataType::LargeBinary => {
+            let array = values.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            let (offsets, values, validity) = generic_binary::take_from_parts::<i64, I>(
+                array.offsets(),
+                array.values(),
+                array.validity().as_ref(),
+                indices,
+            )?;
+            Ok(Box::new(BinaryArray::<i64>::from_data(
+                array.data_type().clone(),
+                offsets,
+                values,
+                validity,
+            )) as Box<dyn Array>)
+        }
+        data_type => Err(ArrowError::NotYetImplemented(format!(
+            "Take is not supported for data type {:?}",
+            data_type
+        ))),
+    }
+}
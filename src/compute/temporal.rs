@@ -0,0 +1,270 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines temporal kernels that extract calendar components of `Timestamp`/`Date32`/
+//! `Date64` arrays into `Int32Array`s.
+
+use chrono::{Datelike, Duration, FixedOffset, NaiveDateTime, Timelike};
+
+use crate::array::{Array, Int32Array, Int64Array, PrimitiveArray};
+use crate::buffer::Buffer;
+use crate::datatypes::{DataType, TimeUnit};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+/// Extracts the year of each non-null element.
+pub fn year(array: &dyn Array) -> Result<Int32Array> {
+    extract(array, |dt| dt.year())
+}
+
+/// Extracts the month (1-12) of each non-null element.
+pub fn month(array: &dyn Array) -> Result<Int32Array> {
+    extract(array, |dt| dt.month() as i32)
+}
+
+/// Extracts the day of the month (1-31) of each non-null element.
+pub fn day(array: &dyn Array) -> Result<Int32Array> {
+    extract(array, |dt| dt.day() as i32)
+}
+
+/// Extracts the hour (0-23) of each non-null element.
+pub fn hour(array: &dyn Array) -> Result<Int32Array> {
+    extract(array, |dt| dt.hour() as i32)
+}
+
+/// Extracts the minute (0-59) of each non-null element.
+pub fn minute(array: &dyn Array) -> Result<Int32Array> {
+    extract(array, |dt| dt.minute() as i32)
+}
+
+/// Extracts the second (0-59) of each non-null element.
+pub fn second(array: &dyn Array) -> Result<Int32Array> {
+    extract(array, |dt| dt.second() as i32)
+}
+
+/// Extracts the ISO weekday (1 = Monday, 7 = Sunday) of each non-null element.
+pub fn weekday(array: &dyn Array) -> Result<Int32Array> {
+    extract(array, |dt| dt.weekday().number_from_monday() as i32)
+}
+
+/// Applies `op` to the `NaiveDateTime` represented by each non-null element of
+/// `array`, which must be `Timestamp`, `Date32` or `Date64`.
+fn extract<F>(array: &dyn Array, op: F) -> Result<Int32Array>
+where
+    F: Fn(NaiveDateTime) -> i32,
+{
+    match array.data_type().clone() {
+        DataType::Timestamp(unit, tz) => {
+            let array = array.as_any().downcast_ref::<Int64Array>().unwrap();
+            let offset = tz.as_deref().and_then(parse_offset);
+            try_unary(array, |value| {
+                let dt = timestamp_to_datetime(value, unit)?;
+                to_datetime_with_offset(dt, offset).map(&op)
+            })
+        }
+        DataType::Date32 => {
+            let array = array.as_any().downcast_ref::<Int32Array>().unwrap();
+            try_unary(array, |value| date32_to_datetime(value).map(&op))
+        }
+        DataType::Date64 => {
+            let array = array.as_any().downcast_ref::<Int64Array>().unwrap();
+            try_unary(array, |value| date64_to_datetime(value).map(&op))
+        }
+        other => Err(ArrowError::NotYetImplemented(format!(
+            "Temporal extraction is not supported for data type {:?}",
+            other
+        ))),
+    }
+}
+
+/// Like `unary`, but `op` is fallible: the first error on a *valid* slot aborts the
+/// whole operation instead of being silently dropped or causing a panic. Null slots
+/// are never passed to `op` (their payload is undefined) and propagate to null slots
+/// in the output, matching the null-skipping idiom used by `divide`/`remainder`.
+fn try_unary<T, F>(array: &PrimitiveArray<T>, op: F) -> Result<Int32Array>
+where
+    T: NativeType,
+    F: Fn(T) -> Result<i32>,
+{
+    let values = if let Some(validity) = array.validity() {
+        let values = array.values().iter().zip(validity.iter()).map(|(value, is_valid)| {
+            if is_valid {
+                op(*value)
+            } else {
+                Ok(i32::default())
+            }
+        });
+        unsafe { Buffer::try_from_trusted_len_iter(values) }
+    } else {
+        let values = array.values().iter().map(|value| op(*value));
+        unsafe { Buffer::try_from_trusted_len_iter(values) }
+    }?;
+
+    Ok(PrimitiveArray::<i32>::from_data(
+        DataType::Int32,
+        values,
+        array.validity().clone(),
+    ))
+}
+
+fn timestamp_to_datetime(value: i64, unit: TimeUnit) -> Result<NaiveDateTime> {
+    let (secs, nanos) = match unit {
+        TimeUnit::Second => (value, 0),
+        TimeUnit::Millisecond => (
+            value.div_euclid(1_000),
+            value.rem_euclid(1_000) as u32 * 1_000_000,
+        ),
+        TimeUnit::Microsecond => (
+            value.div_euclid(1_000_000),
+            value.rem_euclid(1_000_000) as u32 * 1_000,
+        ),
+        TimeUnit::Nanosecond => (
+            value.div_euclid(1_000_000_000),
+            value.rem_euclid(1_000_000_000) as u32,
+        ),
+    };
+    NaiveDateTime::from_timestamp_opt(secs, nanos).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!("Timestamp value {} is out of range", value))
+    })
+}
+
+fn date32_to_datetime(days: i32) -> Result<NaiveDateTime> {
+    NaiveDateTime::from_timestamp_opt(days as i64 * 24 * 60 * 60, 0).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!("Date32 value {} is out of range", days))
+    })
+}
+
+fn date64_to_datetime(millis: i64) -> Result<NaiveDateTime> {
+    NaiveDateTime::from_timestamp_opt(
+        millis.div_euclid(1_000),
+        millis.rem_euclid(1_000) as u32 * 1_000_000,
+    )
+    .ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!("Date64 value {} is out of range", millis))
+    })
+}
+
+fn to_datetime_with_offset(dt: NaiveDateTime, offset: Option<FixedOffset>) -> Result<NaiveDateTime> {
+    match offset {
+        Some(offset) => dt
+            .checked_add_signed(Duration::seconds(offset.local_minus_utc() as i64))
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(
+                    "Applying the timezone offset to the timestamp overflows its representable range"
+                        .to_string(),
+                )
+            }),
+        None => Ok(dt),
+    }
+}
+
+/// Parses a fixed `+HH:MM`/`-HH:MM` timezone offset. Named (IANA) timezones are not
+/// supported and are treated as UTC.
+fn parse_offset(tz: &str) -> Option<FixedOffset> {
+    let (sign, rest) = if let Some(rest) = tz.strip_prefix('+') {
+        (1, rest)
+    } else if let Some(rest) = tz.strip_prefix('-') {
+        (-1, rest)
+    } else {
+        return None;
+    };
+    let mut parts = rest.splitn(2, ':');
+    let hours: i32 = parts.next()?.parse().ok()?;
+    let minutes: i32 = parts.next().unwrap_or("0").parse().ok()?;
+    FixedOffset::east_opt(sign * (hours * 3_600 + minutes * 60))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Primitive;
+    use crate::datatypes::DataType;
+
+    #[test]
+    fn test_year_date32() {
+        // 1970-01-02
+        let array = Primitive::from(&vec![Some(1i32), None]).to(DataType::Date32);
+        let result = year(&array).unwrap();
+        let expected = Primitive::from(&vec![Some(1970), None]).to(DataType::Int32);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_month_timestamp() {
+        // 1970-02-01T00:00:00
+        let seconds = 31 * 24 * 60 * 60;
+        let array = Primitive::from(&vec![Some(seconds as i64)])
+            .to(DataType::Timestamp(TimeUnit::Second, None));
+        let result = month(&array).unwrap();
+        let expected = Primitive::from(&vec![Some(2)]).to(DataType::Int32);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_unsupported_data_type_errors() {
+        let array = Primitive::from(&vec![Some(1i32)]).to(DataType::Int32);
+        assert!(year(&array).is_err());
+    }
+
+    #[test]
+    fn test_year_pre_epoch_millisecond_timestamp() {
+        // 1969-12-31T23:59:59.500, a negative millisecond instant with a non-zero
+        // sub-second remainder
+        let array = Primitive::from(&vec![Some(-500i64)])
+            .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+        let result = year(&array).unwrap();
+        let expected = Primitive::from(&vec![Some(1969)]).to(DataType::Int32);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_second_pre_epoch_millisecond_timestamp() {
+        // 1969-12-31T23:59:59.500
+        let array = Primitive::from(&vec![Some(-500i64)])
+            .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+        let result = second(&array).unwrap();
+        let expected = Primitive::from(&vec![Some(59)]).to(DataType::Int32);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_year_pre_epoch_date64() {
+        // 1969-12-31T23:59:59.500
+        let array = Primitive::from(&vec![Some(-500i64)]).to(DataType::Date64);
+        let result = year(&array).unwrap();
+        let expected = Primitive::from(&vec![Some(1969)]).to(DataType::Int32);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_null_slot_with_unrepresentable_payload_is_skipped() {
+        // the null slot stores `i64::MAX`, which does not convert to a valid
+        // `NaiveDateTime`; it must still propagate to a null output instead of
+        // erroring the whole kernel.
+        let mut validity = crate::bitmap::MutableBitmap::with_capacity(2);
+        validity.push(true);
+        validity.push(false);
+        let array = Int64Array::from_data(
+            DataType::Timestamp(TimeUnit::Second, None),
+            vec![0i64, i64::MAX].into(),
+            Some(validity.into()),
+        );
+        let result = year(&array).unwrap();
+        let expected = Primitive::from(&vec![Some(1970), None]).to(DataType::Int32);
+        assert_eq!(result, expected);
+    }
+}